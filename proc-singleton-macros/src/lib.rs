@@ -0,0 +1,617 @@
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::{
+    Attribute, DeriveInput, ItemStatic, LitStr, Path, Result, StaticMutability, Token, TypePath,
+    Visibility,
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+};
+
+/// The optional `method = ...` / `vis = ...` configuration shared by both macros.
+#[derive(Default)]
+struct AccessorConfig {
+    method: Option<syn::Ident>,
+    vis: Option<Visibility>,
+}
+
+impl AccessorConfig {
+    /// The accessor name, defaulting to `get_instance`.
+    fn method_ident(&self) -> syn::Ident {
+        self.method
+            .clone()
+            .unwrap_or_else(|| syn::Ident::new("get_instance", Span::call_site()))
+    }
+
+    /// The accessor visibility, defaulting to `pub`.
+    fn visibility(&self) -> proc_macro2::TokenStream {
+        match &self.vis {
+            Some(vis) => quote! { #vis },
+            None => quote! { pub },
+        }
+    }
+
+    /// Parse a `method`/`vis` pair, returning `false` if the key is neither.
+    /// The `=` is expected to have been consumed already.
+    fn try_set(&mut self, key: &syn::Ident, input: ParseStream) -> Result<bool> {
+        if key == "method" {
+            self.method = Some(parse_ident_value(input)?);
+            Ok(true)
+        } else if key == "vis" {
+            self.vis = Some(parse_vis_value(input)?);
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+}
+
+/// Read an identifier given either bare (`method = current`) or quoted
+/// (`method = "current"`).
+fn parse_ident_value(input: ParseStream) -> Result<syn::Ident> {
+    if input.peek(LitStr) {
+        input.parse::<LitStr>()?.parse()
+    } else {
+        input.parse()
+    }
+}
+
+/// Read a visibility given either inline (`vis = pub(crate)`) or quoted
+/// (`vis = "pub(crate)"`).
+fn parse_vis_value(input: ParseStream) -> Result<Visibility> {
+    if input.peek(LitStr) {
+        input.parse::<LitStr>()?.parse()
+    } else {
+        input.parse()
+    }
+}
+
+struct SingletonArgs {
+    type_name: TypePath,
+    init_type: Option<Path>,
+    markers: Vec<syn::Ident>,
+    accessor: AccessorConfig,
+}
+
+impl Parse for SingletonArgs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let type_name = input.parse()?;
+        let mut init_type = None;
+        let mut markers = Vec::new();
+        let mut accessor = AccessorConfig::default();
+        while input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+            let ident: syn::Ident = input.parse()?;
+            if input.peek(Token![=]) {
+                input.parse::<Token![=]>()?;
+                if ident == "init_type" {
+                    init_type = Some(input.parse()?);
+                } else if !accessor.try_set(&ident, input)? {
+                    return Err(syn::Error::new(ident.span(), "unknown argument"));
+                }
+            } else {
+                if ident != "Send" && ident != "Sync" {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        "only `Send` and `Sync` markers are accepted",
+                    ));
+                }
+                markers.push(ident);
+            }
+        }
+        Ok(SingletonArgs {
+            type_name,
+            init_type,
+            markers,
+            accessor,
+        })
+    }
+}
+
+/// Create a singleton from a static variable.
+///
+/// # Examples
+/// ```
+/// use std::sync::LazyLock;
+/// use uuid::Uuid;
+/// use proc_singleton::singleton_from_static;
+///
+/// #[singleton_from_static(Identifier)]
+/// static IDENT: LazyLock<Identifier> = LazyLock::new(|| {
+///     Identifier {
+///         id: Uuid::new_v4(),
+///     }
+/// });
+///
+/// struct Identifier {
+///     id: Uuid,
+/// }
+///
+/// fn main() {
+///     let instance = Identifier::get_instance();
+///     let ptr = instance as *const Identifier;
+///     let same_ptr = Identifier::get_instance() as *const Identifier;
+///
+///     assert_eq!(ptr, same_ptr);
+/// }
+/// ```
+///
+/// For an interior-mutable or `!Sync` resource held in a `static mut`, list the
+/// marker traits you are willing to vouch for; this also exposes an
+/// `unsafe fn get_instance_mut()`:
+/// ```ignore
+/// #[singleton_from_static(Peripheral, Send, Sync)]
+/// static mut PERIPHERAL: Peripheral = Peripheral::new();
+/// ```
+/// The markers are applied to a private wrapper that owns the value, not to
+/// `Peripheral` itself, so the type keeps whatever auto traits it had. The
+/// markers are only accepted on a `static mut`; a static whose value wraps an
+/// `UnsafeCell`/`RefCell` is intentionally not special-cased.
+///
+/// The static may also be declared as a plain typed static; it is rewritten
+/// into a `LazyLock` transparently, so the initializer need not be `const`:
+/// ```
+/// use uuid::Uuid;
+/// use proc_singleton::singleton_from_static;
+///
+/// #[singleton_from_static(Identifier)]
+/// static IDENT: Identifier = Identifier { id: Uuid::new_v4() };
+///
+/// struct Identifier {
+///     id: Uuid,
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn singleton_from_static(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(attr as SingletonArgs);
+    let type_name = &args.type_name;
+    let input = parse_macro_input!(item as ItemStatic);
+    let static_name = &input.ident;
+    let is_mut = matches!(input.mutability, StaticMutability::Mut(_));
+    let vis = args.accessor.visibility();
+    let method = args.accessor.method_ident();
+
+    // `init_type` builds into an immutable `OnceLock` cell, so a `static mut`
+    // would be contradictory (and leave `get_instance_mut` borrowing a
+    // non-`mut` static).
+    if args.init_type.is_some() && is_mut {
+        return syn::Error::new(
+            static_name.span(),
+            "`init_type` is not supported on a `static mut`",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    // A plain `static IDENT: Identifier = <expr>;` is transparently rewritten
+    // into a `LazyLock`-backed static so the initializer can be non-const.
+    let needs_lazy_wrap =
+        !is_mut && args.init_type.is_none() && !type_is_lazy_lock(&input.ty);
+    let emitted_static = if args.init_type.is_some() && !type_is_once_lock(&input.ty) {
+        // `init_type` mode synthesizes the `OnceLock` cell itself, keeping the
+        // attached static's name and visibility; its written value is a
+        // placeholder that is discarded,
+        // since the real value is produced by `init` on first access.
+        let attrs = &input.attrs;
+        let static_vis = &input.vis;
+        quote! {
+            #(#attrs)*
+            #static_vis static #static_name: ::std::sync::OnceLock<#type_name> =
+                ::std::sync::OnceLock::new();
+        }
+    } else if needs_lazy_wrap {
+        let attrs = &input.attrs;
+        let static_vis = &input.vis;
+        let ty = &input.ty;
+        let expr = &input.expr;
+        quote! {
+            #(#attrs)*
+            #static_vis static #static_name: ::std::sync::LazyLock<#ty> =
+                ::std::sync::LazyLock::new(|| #expr);
+        }
+    } else {
+        quote! { #input }
+    };
+
+    // Markers vouch for a type the compiler won't auto-mark; that only makes
+    // sense for the interior-mutable `static mut` form. A static wrapping an
+    // `UnsafeCell`/`RefCell` is intentionally out of scope: the trigger is the
+    // `static mut` keyword, not the value's type.
+    if !args.markers.is_empty() && !is_mut {
+        return syn::Error::new(
+            args.markers[0].span(),
+            "`Send`/`Sync` markers are only valid on a `static mut`",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    // `static mut` plus markers: hand the value to a private wrapper that
+    // carries the `unsafe impl`s, so vouching here scopes the assertion to the
+    // singleton rather than silently marking #type_name across the crate.
+    if is_mut && !args.markers.is_empty() {
+        return expand_marked_static_mut(&args, &input);
+    }
+
+    let accessor = match &args.init_type {
+        // Reading a `static mut` may race a writer obtained via
+        // `get_instance_mut`, so the shared accessor is `unsafe` too.
+        None if is_mut => quote! {
+            #vis unsafe fn #method() -> &'static #type_name {
+                unsafe { &*std::ptr::addr_of!(#static_name) }
+            }
+        },
+        None => quote! {
+            #vis fn #method() -> &'static #type_name {
+                &#static_name
+            }
+        },
+        Some(init_type) => quote! {
+            #vis fn #method(init: &#init_type) -> &'static #type_name {
+                #static_name.get_or_init(|| #type_name::init(init))
+            }
+        },
+    };
+
+    let mut_accessor = if is_mut {
+        quote! {
+            #vis unsafe fn get_instance_mut() -> &'static mut #type_name {
+                unsafe { &mut *std::ptr::addr_of_mut!(#static_name) }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let expanded = quote! {
+        #emitted_static
+
+        impl #type_name {
+            #accessor
+            #mut_accessor
+        }
+    };
+
+    expanded.into()
+}
+
+/// Expand the `static mut` + markers form: the value lives inside a private,
+/// `#[doc(hidden)]` wrapper that holds an `UnsafeCell<T>` and carries the
+/// `unsafe impl Send`/`Sync`. The markers therefore apply to the wrapper — not
+/// to the user's type, which keeps its auto-derived marker traits.
+fn expand_marked_static_mut(args: &SingletonArgs, input: &ItemStatic) -> TokenStream {
+    let type_name = &args.type_name;
+    let static_name = &input.ident;
+    let attrs = &input.attrs;
+    let static_vis = &input.vis;
+    let expr = &input.expr;
+    let markers = &args.markers;
+    let vis = args.accessor.visibility();
+    let method = args.accessor.method_ident();
+    let proxy = singleton_proxy_ident(type_name);
+
+    quote! {
+        #[doc(hidden)]
+        #static_vis struct #proxy {
+            inner: ::std::cell::UnsafeCell<#type_name>,
+        }
+        #(unsafe impl #markers for #proxy {})*
+
+        #(#attrs)*
+        #static_vis static #static_name: #proxy = #proxy {
+            inner: ::std::cell::UnsafeCell::new(#expr),
+        };
+
+        impl #type_name {
+            // Reading may race a writer obtained via `get_instance_mut`, so the
+            // shared accessor is `unsafe` too.
+            #vis unsafe fn #method() -> &'static #type_name {
+                unsafe { &*#static_name.inner.get() }
+            }
+            #vis unsafe fn get_instance_mut() -> &'static mut #type_name {
+                unsafe { &mut *#static_name.inner.get() }
+            }
+        }
+    }
+    .into()
+}
+
+/// The private wrapper name for a marked `static mut` singleton, e.g.
+/// `__PeripheralSingleton` for `Peripheral`.
+fn singleton_proxy_ident(type_name: &TypePath) -> syn::Ident {
+    let ident = &type_name
+        .path
+        .segments
+        .last()
+        .expect("a type path has at least one segment")
+        .ident;
+    syn::Ident::new(&format!("__{ident}Singleton"), ident.span())
+}
+
+/// Whether a static's declared type is already a `LazyLock` wrapper, in which
+/// case it is left untouched rather than wrapped again.
+fn type_is_lazy_lock(ty: &syn::Type) -> bool {
+    if let syn::Type::Path(type_path) = ty {
+        if let Some(segment) = type_path.path.segments.last() {
+            return segment.ident == "LazyLock";
+        }
+    }
+    false
+}
+
+/// Whether a static's declared type is already a `OnceLock` cell, in which case
+/// `init_type` mode reuses it rather than synthesizing its own.
+fn type_is_once_lock(ty: &syn::Type) -> bool {
+    if let syn::Type::Path(type_path) = ty {
+        if let Some(segment) = type_path.path.segments.last() {
+            return segment.ident == "OnceLock";
+        }
+    }
+    false
+}
+
+/// Derives a singleton implementation for a struct based on a static variable.
+///
+/// # Examples
+/// ```
+/// use std::sync::LazyLock;
+/// use uuid::Uuid;
+/// use proc_singleton::Singleton;
+///
+/// static IDENT: LazyLock<Identifier> = LazyLock::new(|| {
+///     Identifier {
+///         id: Uuid::new_v4(),
+///     }
+/// });
+/// #[derive(Singleton)]
+/// #[singleton(IDENT)]
+/// struct Identifier {
+///     id: Uuid,
+/// }
+///
+/// fn main() {
+///     let instance = Identifier::get_instance();
+///     let ptr = instance as *const Identifier;
+///     let same_ptr = Identifier::get_instance() as *const Identifier;
+///
+///     assert_eq!(ptr, same_ptr);
+/// }
+/// ```
+///
+/// Alternatively, name an initializer with `init = <path>` and the backing
+/// static is synthesized for you (as a private `IDENTIFIER_SINGLETON` for
+/// struct `Identifier`):
+/// ```
+/// use uuid::Uuid;
+/// use proc_singleton::Singleton;
+///
+/// #[derive(Singleton)]
+/// #[singleton(init = Identifier::build)]
+/// struct Identifier {
+///     id: Uuid,
+/// }
+///
+/// impl Identifier {
+///     fn build() -> Self {
+///         Identifier { id: Uuid::new_v4() }
+///     }
+/// }
+/// ```
+///
+/// For singletons that need runtime configuration at first use, declare an
+/// `init_type` and provide an `init(&Config) -> Self`; the value is built on
+/// the first call to `get_instance(&config)` and reused thereafter:
+/// ```
+/// use uuid::Uuid;
+/// use proc_singleton::Singleton;
+///
+/// struct IdentifierConfig { seed: u128 }
+///
+/// #[derive(Singleton)]
+/// #[singleton(init_type = IdentifierConfig)]
+/// struct Identifier {
+///     id: Uuid,
+/// }
+///
+/// impl Identifier {
+///     fn init(config: &IdentifierConfig) -> Self {
+///         Identifier { id: Uuid::from_u128(config.seed) }
+///     }
+/// }
+/// ```
+///
+/// Add the `trait` flag to implement the [`Singleton`] trait instead of an
+/// inherent method, so downstream code can be generic over `T: Singleton`:
+/// ```
+/// use std::sync::LazyLock;
+/// use uuid::Uuid;
+/// use proc_singleton::Singleton;
+///
+/// static IDENT: LazyLock<Identifier> = LazyLock::new(|| Identifier { id: Uuid::new_v4() });
+///
+/// #[derive(Singleton)]
+/// #[singleton(IDENT, trait)]
+/// struct Identifier {
+///     id: Uuid,
+/// }
+///
+/// fn id_of<T: Singleton + HasId + 'static>() -> Uuid {
+///     T::get_instance().id()
+/// }
+///
+/// trait HasId {
+///     fn id(&self) -> Uuid;
+/// }
+/// impl HasId for Identifier {
+///     fn id(&self) -> Uuid {
+///         self.id
+///     }
+/// }
+/// ```
+#[proc_macro_derive(Singleton, attributes(singleton))]
+pub fn derive_singleton(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let struct_name = &input.ident;
+    let args = match find_singleton_derive_args(&input.attrs) {
+        Ok(args) => args,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let vis = args.accessor.visibility();
+    let method = args.accessor.method_ident();
+
+    // Each source yields an optional backing static, the accessor argument list,
+    // and the expression returning the `&'static` instance.
+    let (prelude, arg, body) = match &args.source {
+        SingletonSource::Static(static_name) => {
+            (quote! {}, quote! {}, quote! { &#static_name })
+        }
+        SingletonSource::Init(init_path) => {
+            let static_name = singleton_static_ident(struct_name);
+            (
+                quote! {
+                    static #static_name: std::sync::OnceLock<#struct_name> =
+                        std::sync::OnceLock::new();
+                },
+                quote! {},
+                quote! { #static_name.get_or_init(|| #init_path()) },
+            )
+        }
+        SingletonSource::InitType(init_type) => {
+            let static_name = singleton_static_ident(struct_name);
+            (
+                quote! {
+                    static #static_name: std::sync::OnceLock<#struct_name> =
+                        std::sync::OnceLock::new();
+                },
+                quote! { init: &#init_type },
+                quote! { #static_name.get_or_init(|| #struct_name::init(init)) },
+            )
+        }
+    };
+
+    let expanded = if args.trait_impl {
+        if !arg.is_empty() {
+            return syn::Error::new(
+                struct_name.span(),
+                "`trait` cannot be combined with `init_type`, whose accessor takes an argument",
+            )
+            .to_compile_error()
+            .into();
+        }
+        quote! {
+            #prelude
+
+            impl proc_singleton::Singleton for #struct_name {
+                fn get_instance() -> &'static Self {
+                    #body
+                }
+            }
+        }
+    } else {
+        quote! {
+            #prelude
+
+            impl #struct_name {
+                #vis fn #method(#arg) -> &'static #struct_name {
+                    #body
+                }
+            }
+        }
+    };
+
+    expanded.into()
+}
+
+/// How the `#[singleton(...)]` attribute asks the derive to obtain its value.
+enum SingletonSource {
+    /// Point at a user-declared `static` holding the instance.
+    Static(syn::Ident),
+    /// Name an initializer; the macro synthesizes the backing static.
+    Init(Path),
+    /// Build the value lazily from a runtime config passed to `get_instance`.
+    InitType(Path),
+}
+
+/// Parsed `#[singleton(...)]` arguments: the value source plus accessor config.
+struct SingletonDeriveArgs {
+    source: SingletonSource,
+    accessor: AccessorConfig,
+    trait_impl: bool,
+}
+
+impl Parse for SingletonSource {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(syn::Ident) && input.peek2(Token![=]) {
+            let key: syn::Ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+            return match key {
+                k if k == "init" => Ok(SingletonSource::Init(input.parse()?)),
+                k if k == "init_type" => Ok(SingletonSource::InitType(input.parse()?)),
+                k => Err(syn::Error::new(
+                    k.span(),
+                    "expected `init = <path>`, `init_type = <type>`, or a bare static name",
+                )),
+            };
+        }
+        Ok(SingletonSource::Static(input.parse()?))
+    }
+}
+
+impl Parse for SingletonDeriveArgs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let source = input.parse()?;
+        let mut accessor = AccessorConfig::default();
+        let mut trait_impl = false;
+        while input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+            // `trait` is a reserved keyword, so it cannot be parsed as an ident.
+            if input.peek(Token![trait]) {
+                input.parse::<Token![trait]>()?;
+                trait_impl = true;
+                continue;
+            }
+            let key: syn::Ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+            if !accessor.try_set(&key, input)? {
+                return Err(syn::Error::new(key.span(), "unknown argument"));
+            }
+        }
+        Ok(SingletonDeriveArgs {
+            source,
+            accessor,
+            trait_impl,
+        })
+    }
+}
+
+fn find_singleton_derive_args(attrs: &[Attribute]) -> Result<SingletonDeriveArgs> {
+    for attr in attrs {
+        if attr.path().is_ident("singleton") {
+            return attr.parse_args::<SingletonDeriveArgs>();
+        }
+    }
+    Err(syn::Error::new(
+        Span::call_site(),
+        "#[singleton(STATIC_NAME)] or #[singleton(init = PATH)] is required",
+    ))
+}
+
+/// Build the hidden static ident for a struct, e.g. `IDENTIFIER_SINGLETON`.
+fn singleton_static_ident(struct_name: &syn::Ident) -> syn::Ident {
+    syn::Ident::new(
+        &format!("{}_SINGLETON", to_shouty_snake_case(&struct_name.to_string())),
+        struct_name.span(),
+    )
+}
+
+/// Convert a type name like `DatabasePool` into `DATABASE_POOL`.
+fn to_shouty_snake_case(ident: &str) -> String {
+    let mut out = String::with_capacity(ident.len());
+    for (i, ch) in ident.char_indices() {
+        if ch.is_uppercase() && i != 0 {
+            out.push('_');
+        }
+        out.push(ch.to_ascii_uppercase());
+    }
+    out
+}
@@ -0,0 +1,18 @@
+//! Ergonomic, boilerplate-free singletons for Rust.
+//!
+//! This crate re-exports the [`Singleton`](macro@Singleton) derive and the
+//! [`singleton_from_static`] attribute from `proc-singleton-macros`, and
+//! defines the [`Singleton`](trait@Singleton) trait that the derive can target.
+
+pub use proc_singleton_macros::{Singleton, singleton_from_static};
+
+/// A process-wide singleton that exposes its shared `'static` instance.
+///
+/// It is implemented automatically by the [`Singleton`](macro@Singleton)
+/// derive when the `trait` flag is given (`#[singleton(IDENT, trait)]`), so
+/// downstream code can be generic over `T: Singleton` — e.g. a registry or a
+/// function accepting any singleton.
+pub trait Singleton {
+    /// Returns the shared, lazily-initialized instance.
+    fn get_instance() -> &'static Self;
+}
@@ -0,0 +1,202 @@
+//! Behavioral coverage for each mode of the two singleton macros.
+
+use proc_singleton::{Singleton, singleton_from_static};
+use std::sync::LazyLock;
+
+// --- derive, pointing at a user-declared static ---------------------------
+
+static CONFIG: LazyLock<Config> = LazyLock::new(|| Config { level: 3 });
+
+#[derive(Singleton)]
+#[singleton(CONFIG)]
+struct Config {
+    level: u8,
+}
+
+#[test]
+fn derive_from_static_returns_one_instance() {
+    assert_eq!(Config::get_instance().level, 3);
+    assert_eq!(
+        Config::get_instance() as *const Config,
+        Config::get_instance() as *const Config,
+    );
+}
+
+// --- derive with `init = <path>`, synthesizing the backing static ----------
+
+#[derive(Singleton)]
+#[singleton(init = Counter::build)]
+struct Counter {
+    start: u32,
+}
+
+impl Counter {
+    fn build() -> Self {
+        Counter { start: 10 }
+    }
+}
+
+#[test]
+fn derive_init_synthesizes_static() {
+    assert_eq!(Counter::get_instance().start, 10);
+    assert_eq!(
+        Counter::get_instance() as *const Counter,
+        Counter::get_instance() as *const Counter,
+    );
+}
+
+// --- derive with `init_type`, configured at first use ----------------------
+
+struct DbConfig {
+    size: usize,
+}
+
+#[derive(Singleton)]
+#[singleton(init_type = DbConfig)]
+struct Db {
+    size: usize,
+}
+
+impl Db {
+    fn init(config: &DbConfig) -> Self {
+        Db { size: config.size }
+    }
+}
+
+#[test]
+fn derive_init_type_builds_once_and_reuses() {
+    let first = Db::get_instance(&DbConfig { size: 7 });
+    assert_eq!(first.size, 7);
+    // The later config is ignored: the value is built only on the first call.
+    let second = Db::get_instance(&DbConfig { size: 99 });
+    assert_eq!(second.size, 7);
+    assert_eq!(first as *const Db, second as *const Db);
+}
+
+// --- attribute on a LazyLock static ----------------------------------------
+
+#[singleton_from_static(Token)]
+static TOKEN: LazyLock<Token> = LazyLock::new(|| Token { value: 42 });
+
+struct Token {
+    value: i32,
+}
+
+#[test]
+fn attr_from_lazylock() {
+    assert_eq!(Token::get_instance().value, 42);
+}
+
+// --- attribute on a plain typed static (auto-wrapped in LazyLock) ----------
+
+#[singleton_from_static(Plain)]
+static PLAIN: Plain = Plain { n: non_const() };
+
+struct Plain {
+    n: u64,
+}
+
+fn non_const() -> u64 {
+    1 + 2
+}
+
+#[test]
+fn attr_plain_static_is_autowrapped() {
+    assert_eq!(Plain::get_instance().n, 3);
+}
+
+// --- attribute with `init_type`, configured at first use -------------------
+
+struct LoggerConfig {
+    level: u8,
+}
+
+// The written value is a placeholder: the macro synthesizes a `OnceLock` cell
+// and the real value comes from `Logger::init` on the first access.
+#[singleton_from_static(Logger, init_type = LoggerConfig)]
+static LOGGER: Logger = Logger { level: 0 };
+
+struct Logger {
+    level: u8,
+}
+
+impl Logger {
+    fn init(config: &LoggerConfig) -> Self {
+        Logger { level: config.level }
+    }
+}
+
+#[test]
+fn attr_init_type_builds_once_and_reuses() {
+    let first = Logger::get_instance(&LoggerConfig { level: 2 });
+    assert_eq!(first.level, 2);
+    let second = Logger::get_instance(&LoggerConfig { level: 9 });
+    assert_eq!(second.level, 2);
+    assert_eq!(first as *const Logger, second as *const Logger);
+}
+
+// --- configurable accessor name and visibility -----------------------------
+
+static NAMED: LazyLock<Named> = LazyLock::new(|| Named { x: 5 });
+
+#[derive(Singleton)]
+#[singleton(NAMED, method = "current", vis = "pub(crate)")]
+struct Named {
+    x: u8,
+}
+
+#[test]
+fn derive_renamed_accessor() {
+    assert_eq!(Named::current().x, 5);
+}
+
+// --- `trait` flag: generic over `T: Singleton` -----------------------------
+
+static SHARED: LazyLock<Shared> = LazyLock::new(|| Shared { tag: 7 });
+
+#[derive(Singleton)]
+#[singleton(SHARED, trait)]
+struct Shared {
+    tag: u8,
+}
+
+trait HasTag {
+    fn tag(&self) -> u8;
+}
+
+impl HasTag for Shared {
+    fn tag(&self) -> u8 {
+        self.tag
+    }
+}
+
+fn tag_of<T: Singleton + HasTag + 'static>() -> u8 {
+    T::get_instance().tag()
+}
+
+#[test]
+fn derive_trait_impl_is_generic() {
+    assert_eq!(tag_of::<Shared>(), 7);
+}
+
+// --- `static mut` with explicit Send/Sync markers --------------------------
+
+#[allow(dead_code)]
+struct Peripheral {
+    ticks: u32,
+    not_sync: std::marker::PhantomData<*const ()>,
+}
+
+#[singleton_from_static(Peripheral, Send, Sync)]
+static mut PERIPHERAL: Peripheral = Peripheral {
+    ticks: 0,
+    not_sync: std::marker::PhantomData,
+};
+
+#[test]
+fn attr_static_mut_with_markers() {
+    unsafe {
+        Peripheral::get_instance_mut().ticks += 1;
+        assert_eq!(Peripheral::get_instance().ticks, 1);
+    }
+}
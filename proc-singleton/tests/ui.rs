@@ -0,0 +1,7 @@
+//! Compile-fail coverage for the macros' `compile_error!` diagnostics.
+
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
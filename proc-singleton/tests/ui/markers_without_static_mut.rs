@@ -0,0 +1,8 @@
+use proc_singleton::singleton_from_static;
+
+#[singleton_from_static(Thing, Send)]
+static THING: Thing = Thing;
+
+struct Thing;
+
+fn main() {}
@@ -0,0 +1,17 @@
+use proc_singleton::Singleton;
+
+#[derive(Singleton)]
+#[singleton(init_type = Cfg, trait)]
+struct Thing {
+    n: u8,
+}
+
+struct Cfg;
+
+impl Thing {
+    fn init(_cfg: &Cfg) -> Self {
+        Thing { n: 0 }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use proc_singleton::singleton_from_static;
+
+#[singleton_from_static(Thing, Clone)]
+static THING: Thing = Thing;
+
+struct Thing;
+
+fn main() {}